@@ -7,10 +7,13 @@
 //!     Regular,
 //!     Directory,
 //!     Symlink,
-//!     BlockDevice, // unix only
-//!     CharDevice,  // unix only
-//!     Fifo,        // unix only
-//!     Socket,      // unix only
+//!     BlockDevice,  // unix only
+//!     CharDevice,   // unix only
+//!     Fifo,         // unix only
+//!     Socket,       // unix only
+//!     SymlinkDir,   // windows only
+//!     SymlinkFile,  // windows only
+//!     ReparsePoint, // windows only
 //! }
 //! ```
 //!
@@ -72,8 +75,11 @@
 //!
 //! # Conversions
 //!
-//! - From [`AsRef<Path>`], [`fs::Metadata`] and [std's `FileType`].
-//! - From and into [`libc::mode_t`] (via the feature `"mode-t-conversion"`).
+//! - From [`AsRef<Path>`] (via [`FileType::read_at`] and [`FileType::symlink_read_at`]), and from
+//!   [`fs::Metadata`] and [std's `FileType`] fallibly via `TryFrom`, since those types can
+//!   represent file types this crate doesn't have a variant for.
+//! - From and into [`libc::mode_t`] (via the feature `"mode-t-conversion"`), also fallibly in the
+//!   `mode_t -> FileType` direction, see [`FileType::try_from_mode_t`].
 //!
 //! [`AsRef<Path>`]: https://doc.rust-lang.org/std/path/struct.Path.html
 //! [`fs::Metadata`]: https://doc.rust-lang.org/std/fs/struct.Metadata.html
@@ -85,24 +91,43 @@ mod mode_t_conversion_feature;
 
 #[cfg(unix)]
 use std::os::unix::fs::FileTypeExt;
-use std::{fmt, fs, io, path::Path};
-
-#[cfg(feature = "mode-t-conversion")]
-pub use mode_t_conversion_feature::*;
+#[cfg(windows)]
+use std::os::windows::fs::FileTypeExt;
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
 
 /// An enum with a variant for each file type.
 ///
-/// ```
-/// # use file_type_enum::FileType;
-/// # let file_type = FileType::read_at("src/").unwrap();
+/// The variants differ by platform, so the exhaustive match below is split per platform instead
+/// of being compiled (and run) as a single doctest, which would require every variant to exist on
+/// every target.
+///
+/// On unix:
+///
+/// ```ignore
 /// match file_type {
 ///     FileType::Regular     => {},
 ///     FileType::Directory   => {},
 ///     FileType::Symlink     => {},
-///     FileType::BlockDevice => {}, // unix only
-///     FileType::CharDevice  => {}, // unix only
-///     FileType::Fifo        => {}, // unix only
-///     FileType::Socket      => {}, // unix only
+///     FileType::BlockDevice => {},
+///     FileType::CharDevice  => {},
+///     FileType::Fifo        => {},
+///     FileType::Socket      => {},
+/// }
+/// ```
+///
+/// On windows:
+///
+/// ```ignore
+/// match file_type {
+///     FileType::Regular      => {},
+///     FileType::Directory    => {},
+///     FileType::Symlink      => {},
+///     FileType::SymlinkDir   => {},
+///     FileType::SymlinkFile  => {},
+///     FileType::ReparsePoint => {},
 /// }
 /// ```
 #[rustfmt::skip]
@@ -113,6 +138,10 @@ pub enum FileType {
     /// A directory, folder of files.
     Directory,
     /// A symbolic link, points to another path.
+    ///
+    /// On unix this is the only symlink variant. On windows, see also `FileType::SymlinkDir`
+    /// and `FileType::SymlinkFile`, which are reported instead of this one whenever the
+    /// distinction is known.
     Symlink,
     /// Unix block device.
     #[cfg(unix)] BlockDevice,
@@ -122,6 +151,22 @@ pub enum FileType {
     #[cfg(unix)] Fifo,
     /// Unix socket.
     #[cfg(unix)] Socket,
+    /// Windows symlink that points to a directory.
+    ///
+    /// Windows only exposes a "name surrogate" bit on reparse points, which directory
+    /// junctions/mount points also set, so this variant can also mean a junction or mount point
+    /// rather than a real symlink; `std` gives no way to tell them apart without reading the raw
+    /// reparse tag.
+    #[cfg(windows)] SymlinkDir,
+    /// Windows symlink that points to a file.
+    #[cfg(windows)] SymlinkFile,
+    /// Windows reparse point that `std` cannot otherwise classify.
+    ///
+    /// Currently unreachable: every reparse point `std::fs::FileType` exposes through its public
+    /// API either is a name surrogate (caught by [`FileType::SymlinkDir`]/
+    /// [`FileType::SymlinkFile`]/[`FileType::Symlink`]) or gets reported as a plain directory or
+    /// file. Kept for a future conversion that reads the raw reparse tag directly.
+    #[cfg(windows)] ReparsePoint,
 }
 
 impl FileType {
@@ -132,11 +177,11 @@ impl FileType {
     /// # Errors
     ///
     /// - Path does not exist, or
-    /// - Current user lacks permissions to read `fs::Metadata` of `path`.
+    /// - Current user lacks permissions to read `fs::Metadata` of `path`, or
+    /// - The OS reports a file type this crate has no [`FileType`] variant for.
     pub fn read_at(path: impl AsRef<Path>) -> io::Result<Self> {
         let fs_file_type = fs::metadata(path.as_ref())?.file_type();
-        let result = FileType::from(fs_file_type);
-        Ok(result)
+        FileType::try_from(fs_file_type).map_err(io::Error::other)
     }
 
     /// Reads a `FileType` from a path, considers symlinks.
@@ -147,11 +192,27 @@ impl FileType {
     /// # Errors
     ///
     /// - Path does not exist, or
-    /// - Current user lacks permissions to read `fs::Metadata` of `path`.
+    /// - Current user lacks permissions to read `fs::Metadata` of `path`, or
+    /// - The OS reports a file type this crate has no [`FileType`] variant for.
     pub fn symlink_read_at(path: impl AsRef<Path>) -> io::Result<Self> {
         let fs_file_type = fs::symlink_metadata(path.as_ref())?.file_type();
-        let result = FileType::from(fs_file_type);
-        Ok(result)
+        FileType::try_from(fs_file_type).map_err(io::Error::other)
+    }
+
+    /// Reads a `FileType` from an already-open [`fs::File`].
+    ///
+    /// Unlike [`FileType::read_at`], this does not re-resolve `path`, so it reflects the object
+    /// the open file descriptor actually refers to, even if the path was since renamed or
+    /// replaced by something else. Prefer this over a path-based lookup when you already hold
+    /// the `File` and want to avoid that time-of-check/time-of-use race.
+    ///
+    /// # Errors
+    ///
+    /// - Current user lacks permissions to read `fs::Metadata` of `file`, or
+    /// - The OS reports a file type this crate has no [`FileType`] variant for.
+    pub fn from_file(file: &fs::File) -> io::Result<Self> {
+        let fs_file_type = file.metadata()?.file_type();
+        FileType::try_from(fs_file_type).map_err(io::Error::other)
     }
 
     /// Returns true if is a [`FileType::Regular`].
@@ -164,9 +225,14 @@ impl FileType {
         matches!(self, FileType::Directory)
     }
 
-    /// Returns true if is a [`FileType::Symlink`].
+    /// Returns true if is a [`FileType::Symlink`], or, on windows, a `FileType::SymlinkDir` or
+    /// `FileType::SymlinkFile`.
     pub fn is_symlink(&self) -> bool {
-        matches!(self, FileType::Symlink)
+        #[cfg(windows)]
+        let result = matches!(self, FileType::Symlink | FileType::SymlinkDir | FileType::SymlinkFile);
+        #[cfg(not(windows))]
+        let result = matches!(self, FileType::Symlink);
+        result
     }
 
     /// Returns true if is a [`FileType::BlockDevice`].
@@ -192,10 +258,258 @@ impl FileType {
     pub fn is_socket(&self) -> bool {
         matches!(self, FileType::Socket)
     }
+
+    /// Returns true if is a [`FileType::SymlinkDir`].
+    #[cfg(windows)]
+    pub fn is_symlink_dir(&self) -> bool {
+        matches!(self, FileType::SymlinkDir)
+    }
+
+    /// Returns true if is a [`FileType::SymlinkFile`].
+    #[cfg(windows)]
+    pub fn is_symlink_file(&self) -> bool {
+        matches!(self, FileType::SymlinkFile)
+    }
+
+    /// Returns true if is a [`FileType::ReparsePoint`].
+    #[cfg(windows)]
+    pub fn is_reparse_point(&self) -> bool {
+        matches!(self, FileType::ReparsePoint)
+    }
+
+    /// Classifies every entry of a directory in one pass, without extra `stat` calls per entry.
+    ///
+    /// Builds each `FileType` straight from [`fs::DirEntry::file_type`], which many platforms
+    /// serve directly off the directory read and only fall back to a `stat` when the OS didn't
+    /// provide the type. This avoids double-stating every file compared to calling
+    /// [`FileType::symlink_read_at`] on each entry yourself.
+    ///
+    /// This does not follow symlinks: entries that are themselves symlinks are reported as
+    /// [`FileType::Symlink`]. See [`FileType::read_dir_typed_follow_symlinks`] if you want
+    /// symlinks resolved to the type they point to.
+    ///
+    /// # Errors
+    ///
+    /// - `path` does not exist, or
+    /// - Current user lacks permissions to read `path`, or
+    /// - Reading an entry's type fails, or it has no corresponding `FileType` variant.
+    pub fn read_dir_typed(
+        path: impl AsRef<Path>,
+    ) -> io::Result<impl Iterator<Item = io::Result<(PathBuf, FileType)>>> {
+        let entries = fs::read_dir(path.as_ref())?;
+        Ok(entries.map(|entry| {
+            let entry = entry?;
+            let file_type = FileType::try_from(entry.file_type()?)
+                .map_err(io::Error::other)?;
+            Ok((entry.path(), file_type))
+        }))
+    }
+
+    /// Like [`FileType::read_dir_typed`], but follows symlinks, reporting the type they point to
+    /// instead of [`FileType::Symlink`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`FileType::read_dir_typed`], plus any error produced by following an entry's
+    /// symlink (e.g. a broken link).
+    pub fn read_dir_typed_follow_symlinks(
+        path: impl AsRef<Path>,
+    ) -> io::Result<impl Iterator<Item = io::Result<(PathBuf, FileType)>>> {
+        let entries = fs::read_dir(path.as_ref())?;
+        Ok(entries.map(|entry| {
+            let entry = entry?;
+            let path = entry.path();
+            let file_type = FileType::read_at(&path)?;
+            Ok((path, file_type))
+        }))
+    }
+
+    /// Creates a filesystem node of this variant's kind at `path`.
+    ///
+    /// This is the inverse of [`FileType::read_at`]: given a variant, it materializes a node of
+    /// the matching type, which is handy for setting up test fixtures or for tools that must
+    /// reproduce every file type a filesystem can hold.
+    ///
+    /// `FileType::Symlink` has no single target to create from, use
+    /// [`FileType::create_symlink_at`] for that case instead. Likewise, `FileType::BlockDevice`
+    /// and `FileType::CharDevice` need a device number, use [`FileType::create_device_at`].
+    ///
+    /// # Errors
+    ///
+    /// - A node already exists at `path`, or
+    /// - Current user lacks permissions to create `path`, or
+    /// - `self` is a variant that cannot be created through this function (see above).
+    pub fn create_at(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match self {
+            FileType::Regular => {
+                fs::OpenOptions::new().write(true).create_new(true).open(path)?;
+                Ok(())
+            }
+            FileType::Directory => fs::create_dir(path),
+            FileType::Symlink => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "FileType::Symlink has no single target, use FileType::create_symlink_at instead",
+            )),
+            #[cfg(unix)]
+            FileType::Fifo => unix_mkfifo(path),
+            #[cfg(unix)]
+            FileType::Socket => {
+                std::os::unix::net::UnixListener::bind(path)?;
+                Ok(())
+            }
+            #[cfg(unix)]
+            FileType::BlockDevice | FileType::CharDevice => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "block and char devices need a device number, use FileType::create_device_at instead",
+            )),
+            #[cfg(windows)]
+            FileType::SymlinkDir | FileType::SymlinkFile => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "windows symlink variants have no single target, use FileType::create_symlink_at instead",
+            )),
+            #[cfg(windows)]
+            FileType::ReparsePoint => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "FileType::ReparsePoint cannot be created generically",
+            )),
+        }
+    }
+
+    /// Creates a unix block or char device node at `path` with device number `dev`.
+    ///
+    /// `dev` is the raw device number as consumed by `mknod(2)`, usually built with
+    /// `libc::makedev`.
+    ///
+    /// # Errors
+    ///
+    /// - `self` is not [`FileType::BlockDevice`] or [`FileType::CharDevice`], or
+    /// - A node already exists at `path`, or
+    /// - Current user lacks permissions to create `path`.
+    #[cfg(unix)]
+    pub fn create_device_at(&self, path: impl AsRef<Path>, dev: libc::dev_t) -> io::Result<()> {
+        let mode = match self {
+            FileType::BlockDevice => libc::S_IFBLK,
+            FileType::CharDevice => libc::S_IFCHR,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "FileType::create_device_at is only valid for BlockDevice and CharDevice",
+                ))
+            }
+        };
+        unix_mknod(path.as_ref(), mode, dev)
+    }
+
+    /// Creates a symlink at `path` pointing to `target`.
+    ///
+    /// Unlike the other variants, a symlink needs a target to point to, so it is built through
+    /// this dedicated method instead of [`FileType::create_at`].
+    ///
+    /// On windows, creating a symlink requires knowing upfront whether it points to a directory
+    /// or a file. Call this on `FileType::SymlinkDir` or `FileType::SymlinkFile` to say so
+    /// explicitly; calling it on the cross-platform [`FileType::Symlink`] instead stats `target`
+    /// to find out, which fails if `target` doesn't exist yet, as is common when reproducing
+    /// dangling or forward-created symlinks, use an explicit variant in that case.
+    ///
+    /// # Errors
+    ///
+    /// - `self` is not [`FileType::Symlink`] or, on windows, `FileType::SymlinkDir`/
+    ///   `FileType::SymlinkFile`, or
+    /// - A node already exists at `path`, or
+    /// - Current user lacks permissions to create `path`, or
+    /// - On windows, called through [`FileType::Symlink`] and `target`'s metadata cannot be read
+    ///   to tell whether to create a directory or file symlink.
+    pub fn create_symlink_at(&self, path: impl AsRef<Path>, target: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let target = target.as_ref();
+
+        #[cfg(unix)]
+        {
+            match self {
+                FileType::Symlink => std::os::unix::fs::symlink(target, path),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "FileType::create_symlink_at is only valid for FileType::Symlink",
+                )),
+            }
+        }
+        #[cfg(windows)]
+        {
+            let is_dir = match self {
+                FileType::SymlinkDir => true,
+                FileType::SymlinkFile => false,
+                FileType::Symlink => fs::metadata(target)?.is_dir(),
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "FileType::create_symlink_at is only valid for FileType::Symlink, \
+                         FileType::SymlinkDir, or FileType::SymlinkFile",
+                    ))
+                }
+            };
+            if is_dir {
+                std::os::windows::fs::symlink_dir(target, path)
+            } else {
+                std::os::windows::fs::symlink_file(target, path)
+            }
+        }
+    }
+}
+
+/// Converts `path` into a NUL-terminated `CString` suitable for libc calls.
+#[cfg(unix)]
+fn path_to_cstring(path: &Path) -> io::Result<std::ffi::CString> {
+    use std::os::unix::ffi::OsStrExt;
+    std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
+
+#[cfg(unix)]
+fn unix_mkfifo(path: &Path) -> io::Result<()> {
+    let c_path = path_to_cstring(path)?;
+    match unsafe { libc::mkfifo(c_path.as_ptr(), 0o644) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+#[cfg(unix)]
+fn unix_mknod(path: &Path, file_type_bits: libc::mode_t, dev: libc::dev_t) -> io::Result<()> {
+    let c_path = path_to_cstring(path)?;
+    match unsafe { libc::mknod(c_path.as_ptr(), file_type_bits | 0o644, dev) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
 }
 
-impl From<fs::FileType> for FileType {
-    fn from(ft: fs::FileType) -> Self {
+/// Error returned when a filesystem file type has no corresponding [`FileType`] variant.
+///
+/// This shows up on exotic platforms or future kernel file types this crate doesn't know about
+/// yet, rather than as a panic, so robust callers can handle it instead of aborting.
+#[derive(Debug)]
+pub struct UnknownFileType {
+    debug: String,
+}
+
+impl UnknownFileType {
+    fn new(source: impl fmt::Debug) -> Self {
+        UnknownFileType { debug: format!("{source:?}") }
+    }
+}
+
+impl fmt::Display for UnknownFileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown or unsupported file type: {}", self.debug)
+    }
+}
+
+impl std::error::Error for UnknownFileType {}
+
+impl TryFrom<fs::FileType> for FileType {
+    type Error = UnknownFileType;
+
+    fn try_from(ft: fs::FileType) -> Result<Self, Self::Error> {
         // Check each type
         #[cfg(unix)]
         let result = {
@@ -214,11 +528,42 @@ impl From<fs::FileType> for FileType {
             } else if ft.is_socket() {
                 FileType::Socket
             } else {
-                unreachable!("file_type_enum: unexpected file type: {:?}.", ft)
+                return Err(UnknownFileType::new(ft));
+            }
+        };
+
+        #[cfg(windows)]
+        let result = {
+            // std derives `is_symlink_dir`/`is_symlink_file`/`is_symlink` from the reparse-tag
+            // "name surrogate" bit, and `is_dir`/`is_file` already exclude it internally
+            // (`is_dir() == !is_symlink() && <directory attribute set>`), so a symlink never
+            // matches the `is_dir`/`is_file` arms and the order of these checks doesn't change
+            // the result.
+            if ft.is_symlink_dir() {
+                FileType::SymlinkDir
+            } else if ft.is_symlink_file() {
+                FileType::SymlinkFile
+            } else if ft.is_symlink() {
+                // A name-surrogate reparse point whose target kind std couldn't determine,
+                // fall back to the cross-platform variant.
+                FileType::Symlink
+            } else if ft.is_dir() {
+                FileType::Directory
+            } else if ft.is_file() {
+                FileType::Regular
+            } else {
+                // Unreachable through `std`'s public API: every `fs::FileType` that isn't a
+                // name-surrogate reparse point (the only kind `is_symlink*` can see) is reported
+                // as either a directory or a file by `is_dir`/`is_file`. Kept so a future
+                // path-based conversion — able to read the raw reparse tag via
+                // `FSCTL_GET_REPARSE_POINT` and actually distinguish non-symlink reparse points
+                // (junctions/mount points, cloud placeholders, dedup files) from real symlinks —
+                // has somewhere to report them without widening this match.
+                FileType::ReparsePoint
             }
         };
 
-        #[cfg(not(unix))]
+        #[cfg(not(any(unix, windows)))]
         let result = {
             if ft.is_file() {
                 FileType::Regular
@@ -227,17 +572,19 @@ impl From<fs::FileType> for FileType {
             } else if ft.is_symlink() {
                 FileType::Symlink
             } else {
-                unreachable!("file_type_enum: unexpected file type: {:?}.", ft)
+                return Err(UnknownFileType::new(ft));
             }
         };
 
-        result
+        Ok(result)
     }
 }
 
-impl From<fs::Metadata> for FileType {
-    fn from(metadata: fs::Metadata) -> Self {
-        metadata.file_type().into()
+impl TryFrom<fs::Metadata> for FileType {
+    type Error = UnknownFileType;
+
+    fn try_from(metadata: fs::Metadata) -> Result<Self, Self::Error> {
+        metadata.file_type().try_into()
     }
 }
 
@@ -252,12 +599,17 @@ impl fmt::Display for FileType {
             #[cfg(unix)] FileType::CharDevice => write!(f, "char device"),
             #[cfg(unix)] FileType::Fifo => write!(f, "FIFO"),
             #[cfg(unix)] FileType::Socket => write!(f, "socket"),
+            #[cfg(windows)] FileType::SymlinkDir => write!(f, "directory symbolic link"),
+            #[cfg(windows)] FileType::SymlinkFile => write!(f, "file symbolic link"),
+            #[cfg(windows)] FileType::ReparsePoint => write!(f, "reparse point"),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io;
+
     use super::FileType;
 
     #[test]
@@ -265,4 +617,163 @@ mod tests {
         let this_file = FileType::read_at("src/lib.rs").unwrap();
         assert!(this_file.is_regular());
     }
+
+    /// Returns a fresh, empty temporary directory scoped to `name`, so tests that create
+    /// fixtures on disk don't collide with each other.
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("file_type_enum_test_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_create_at_regular_and_directory() {
+        let dir = test_dir("create_at_regular_and_directory");
+        let file_path = dir.join("file");
+        let dir_path = dir.join("dir");
+
+        FileType::Regular.create_at(&file_path).unwrap();
+        assert!(FileType::read_at(&file_path).unwrap().is_regular());
+
+        FileType::Directory.create_at(&dir_path).unwrap();
+        assert!(FileType::read_at(&dir_path).unwrap().is_directory());
+    }
+
+    #[test]
+    fn test_create_at_symlink_errs() {
+        let dir = test_dir("create_at_symlink_errs");
+        assert!(FileType::Symlink.create_at(dir.join("symlink")).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_at_fifo_and_socket() {
+        let dir = test_dir("create_at_fifo_and_socket");
+        let fifo_path = dir.join("fifo");
+        let socket_path = dir.join("socket");
+
+        FileType::Fifo.create_at(&fifo_path).unwrap();
+        assert!(FileType::symlink_read_at(&fifo_path).unwrap().is_fifo());
+
+        FileType::Socket.create_at(&socket_path).unwrap();
+        assert!(FileType::symlink_read_at(&socket_path).unwrap().is_socket());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_at_device_variants_err() {
+        let dir = test_dir("create_at_device_variants_err");
+        assert!(FileType::BlockDevice.create_at(dir.join("blockdev")).is_err());
+        assert!(FileType::CharDevice.create_at(dir.join("chardev")).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_device_at_rejects_non_device_variant() {
+        let dir = test_dir("create_device_at_rejects_non_device_variant");
+        let err = FileType::Regular
+            .create_device_at(dir.join("not-a-device"), 0)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_symlink_at() {
+        let dir = test_dir("create_symlink_at");
+        let target = dir.join("target");
+        let link = dir.join("link");
+        FileType::Regular.create_at(&target).unwrap();
+
+        FileType::Symlink.create_symlink_at(&link, &target).unwrap();
+        assert!(FileType::symlink_read_at(&link).unwrap().is_symlink());
+        assert!(FileType::read_at(&link).unwrap().is_regular());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_symlink_at_rejects_non_symlink_variant() {
+        let dir = test_dir("create_symlink_at_rejects_non_symlink_variant");
+        let err = FileType::Regular
+            .create_symlink_at(dir.join("link"), dir.join("target"))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_from_file() {
+        let dir = test_dir("from_file");
+        let file_path = dir.join("file");
+        FileType::Regular.create_at(&file_path).unwrap();
+
+        let file = std::fs::File::open(&file_path).unwrap();
+        assert!(FileType::from_file(&file).unwrap().is_regular());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_from_file_directory() {
+        let dir = test_dir("from_file_directory");
+
+        let file = std::fs::File::open(&dir).unwrap();
+        assert!(FileType::from_file(&file).unwrap().is_directory());
+    }
+
+    #[test]
+    fn test_read_dir_typed() {
+        let dir = test_dir("read_dir_typed");
+        FileType::Regular.create_at(dir.join("file")).unwrap();
+        FileType::Directory.create_at(dir.join("subdir")).unwrap();
+
+        let mut entries: Vec<_> = FileType::read_dir_typed(&dir)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+        entries.sort();
+
+        assert_eq!(
+            entries,
+            vec![
+                (dir.join("file"), FileType::Regular),
+                (dir.join("subdir"), FileType::Directory),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_dir_typed_does_not_follow_symlinks() {
+        let dir = test_dir("read_dir_typed_does_not_follow_symlinks");
+        let target = dir.join("target");
+        let link = dir.join("link");
+        FileType::Regular.create_at(&target).unwrap();
+        FileType::Symlink.create_symlink_at(&link, &target).unwrap();
+
+        let entries: Vec<_> = FileType::read_dir_typed(&dir)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        let (_, link_type) = entries.iter().find(|(path, _)| *path == link).unwrap();
+        assert!(link_type.is_symlink());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_dir_typed_follow_symlinks() {
+        let dir = test_dir("read_dir_typed_follow_symlinks");
+        let target = dir.join("target");
+        let link = dir.join("link");
+        FileType::Regular.create_at(&target).unwrap();
+        FileType::Symlink.create_symlink_at(&link, &target).unwrap();
+
+        let entries: Vec<_> = FileType::read_dir_typed_follow_symlinks(&dir)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        let (_, link_type) = entries.iter().find(|(path, _)| *path == link).unwrap();
+        assert!(link_type.is_regular());
+    }
 }
@@ -1,32 +1,41 @@
 use libc::mode_t;
 
-use crate::FileType;
+use crate::{FileType, UnknownFileType};
 
-impl From<mode_t> for FileType {
-    fn from(bits: mode_t) -> Self {
-        match bits {
+impl FileType {
+    /// Tries to convert a raw `mode_t` (as returned by `stat`) into a `FileType`.
+    ///
+    /// `bits` is a full `st_mode` value, so the type lives in the `S_IFMT` nibble and is masked
+    /// out before matching, the rest are permission bits this function doesn't care about. The
+    /// conversion is fallible because the kernel can report file types this crate has no variant
+    /// for.
+    ///
+    /// # Errors
+    ///
+    /// - `bits`'s `S_IFMT` nibble does not match any known file type.
+    pub fn try_from_mode_t(bits: mode_t) -> Result<Self, UnknownFileType> {
+        let result = match bits & libc::S_IFMT {
             libc::S_IFREG => FileType::Regular,
             libc::S_IFDIR => FileType::Directory,
-            libc::S_IFCHR => FileType::Symlink,
+            libc::S_IFLNK => FileType::Symlink,
             libc::S_IFBLK => FileType::BlockDevice,
-            libc::S_IFIFO => FileType::CharDevice,
-            libc::S_IFLNK => FileType::Fifo,
+            libc::S_IFCHR => FileType::CharDevice,
+            libc::S_IFIFO => FileType::Fifo,
             libc::S_IFSOCK => FileType::Socket,
-            _ => unreachable!(),
-        }
+            _ => return Err(UnknownFileType::new(bits)),
+        };
+        Ok(result)
     }
-}
 
-impl FileType {
     /// Convert [`FileType`] into the [`libc`] integer bitmask equivalent.
     pub fn bits(&self) -> mode_t {
         match self {
             FileType::Regular => libc::S_IFREG,
             FileType::Directory => libc::S_IFDIR,
-            FileType::Symlink => libc::S_IFCHR,
+            FileType::Symlink => libc::S_IFLNK,
             FileType::BlockDevice => libc::S_IFBLK,
-            FileType::CharDevice => libc::S_IFIFO,
-            FileType::Fifo => libc::S_IFLNK,
+            FileType::CharDevice => libc::S_IFCHR,
+            FileType::Fifo => libc::S_IFIFO,
             FileType::Socket => libc::S_IFSOCK,
         }
     }
@@ -44,10 +53,39 @@ mod tests {
 
     #[test]
     fn test_mode_t_conversion() {
-        assert_eq!(libc::S_IFDIR, FileType::from_path("src/").unwrap().bits());
+        assert_eq!(libc::S_IFDIR, FileType::read_at("src/").unwrap().bits());
         assert_eq!(
             libc::S_IFREG,
-            FileType::from_path("src/lib.rs").unwrap().bits()
+            FileType::read_at("src/lib.rs").unwrap().bits()
         );
     }
+
+    #[test]
+    fn test_mode_t_round_trip() {
+        let constants = [
+            (libc::S_IFREG, FileType::Regular),
+            (libc::S_IFDIR, FileType::Directory),
+            (libc::S_IFLNK, FileType::Symlink),
+            (libc::S_IFBLK, FileType::BlockDevice),
+            (libc::S_IFCHR, FileType::CharDevice),
+            (libc::S_IFIFO, FileType::Fifo),
+            (libc::S_IFSOCK, FileType::Socket),
+        ];
+
+        for (bits, file_type) in constants {
+            assert_eq!(FileType::try_from_mode_t(bits).unwrap(), file_type);
+            assert_eq!(file_type.bits(), bits);
+        }
+    }
+
+    #[test]
+    fn test_mode_t_conversion_masks_out_permission_bits() {
+        // `0o100644` is a real `st_mode` value for a regular file with `rw-r--r--` permissions.
+        assert_eq!(FileType::try_from_mode_t(0o100644).unwrap(), FileType::Regular);
+    }
+
+    #[test]
+    fn test_mode_t_conversion_rejects_unknown_type() {
+        assert!(FileType::try_from_mode_t(0).is_err());
+    }
 }